@@ -1,60 +1,135 @@
-        
-        use actix_web::{
+use actix_web::{
     get, post, web, HttpResponse, Responder, Result,
     error::ErrorInternalServerError,
 };
 use chrono::Utc;
+use futures_util::StreamExt;
 use log::{error, info};
+use ethers::types::Address;
+use mongodb::bson;
 use reqwest::Client as HttpClient;
 use serde_json::json;
 use std::env;
+use std::str::FromStr;
+use tokio_stream::wrappers::BroadcastStream;
 
 use crate::db::DbClient;
-use crate::ipc::{extract_subnet_id, is_valid_ipc_address};
-use crate::ipc::client::IPCClient;
+use crate::ipc::monitor::AlertSender;
+use crate::ipc::{extract_eth_address, extract_subnet_id, is_ens_name, is_valid_ipc_address};
+use crate::ipc::client::{IPCClient, TRANSFER_ACTIVITY_BLOCK_WINDOW};
 use crate::models::{
     AIPredictionRequest, AIPredictionResponse,
     WalletCheck, WalletCheckRequest, WalletCheckResponse,
-    SubnetStats,
+    SubnetStats, WatchRequest,
 };
 
-#[post("/check")]
-async fn check_wallet(
-    db: web::Data,
-    req: web::Json,
-) -> Result {
-    let address = req.address.clone();
-    info!("Checking wallet address: {}", address);
-    
-    // Validate address format
+/// Errors shared between the REST handlers and the JSON-RPC dispatcher so
+/// both surfaces report the same failures for the same underlying causes.
+#[derive(Debug)]
+pub enum CoreError {
+    InvalidAddress,
+    EnsResolutionFailed(String),
+    IpcUnavailable,
+    Database(String),
+    AiService(String),
+}
+
+/// Core `/check` flow: resolves ENS names, derives trace/transfer
+/// signals, records the check, scores it via the AI service, and persists
+/// the result. Shared by the REST handler and the `check_wallet` RPC method.
+pub async fn run_check_wallet(
+    db: &DbClient,
+    requested_address: String,
+) -> std::result::Result<WalletCheckResponse, CoreError> {
+    info!("Checking wallet address: {}", requested_address);
+
+    // Pull trace-, log-, and ENS-resolution signals off the same client. A
+    // client/node that can't serve traces or logs simply yields no extra
+    // signal rather than failing the check outright; ENS resolution
+    // failures are reported back to the caller, since without it there's
+    // no address left to check.
+    let ipc_client = match IPCClient::new() {
+        Ok(client) => Some(client),
+        Err(e) => {
+            error!("Failed to create IPC client: {}", e);
+            None
+        }
+    };
+
+    let (address, resolved_from) = if is_ens_name(&requested_address) {
+        let client = ipc_client.as_ref().ok_or(CoreError::IpcUnavailable)?;
+
+        match client.resolve_ens_name(&requested_address).await {
+            Ok(resolved) => (format!("{:?}", resolved), Some(requested_address.clone())),
+            Err(e) => {
+                error!("Failed to resolve ENS name {}: {}", requested_address, e);
+                return Err(CoreError::EnsResolutionFailed(requested_address));
+            }
+        }
+    } else {
+        (requested_address.clone(), None)
+    };
+
     if !is_valid_ipc_address(&address) {
-        return Ok(HttpResponse::BadRequest().json(json!({
-            "error": "Invalid IPC or Ethereum address format"
-        })));
+        return Err(CoreError::InvalidAddress);
     }
-    
-    // Extract subnet ID if present
+
     let subnet_id = extract_subnet_id(&address);
-    
+
+    let ipc_specific_flags = match &ipc_client {
+        Some(client) => match client.get_fraud_flags(&address).await {
+            Ok(flags) if flags.is_empty() => None,
+            Ok(flags) => Some(flags),
+            Err(e) => {
+                error!("Failed to derive trace flags for {}: {}", address, e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    let transfer_activity = match (
+        &ipc_client,
+        extract_eth_address(&address).and_then(|a| Address::from_str(&a).ok()),
+    ) {
+        (Some(client), Some(eth_addr)) => {
+            match client
+                .get_transfer_activity(eth_addr, TRANSFER_ACTIVITY_BLOCK_WINDOW)
+                .await
+            {
+                Ok(activity) => Some(activity),
+                Err(e) => {
+                    error!("Failed to derive transfer activity for {}: {}", address, e);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
     // Create initial wallet check record
-    let wallet_check = WalletCheck::new(address.clone(), subnet_id.clone());
-    
+    let mut wallet_check = WalletCheck::new(address.clone(), subnet_id.clone());
+    wallet_check.ipc_specific_flags = ipc_specific_flags.clone();
+    wallet_check.resolved_from = resolved_from.clone();
+
     // Store initial record in MongoDB
-    if let Err(e) = db.insert_wallet_check(wallet_check).await {
+    db.insert_wallet_check(wallet_check).await.map_err(|e| {
         error!("Failed to insert wallet check: {}", e);
-        return Err(ErrorInternalServerError("Database error"));
-    }
-    
+        CoreError::Database(e.to_string())
+    })?;
+
     // Send request to AI service
     let ai_service_url = env::var("AI_SERVICE_URL")
         .unwrap_or_else(|_| "http://localhost:8000".to_string());
-    
+
     let http_client = HttpClient::new();
-    let ai_request = AIPredictionRequest { 
+    let ai_request = AIPredictionRequest {
         address: address.clone(),
         subnet_id: subnet_id.clone(),
+        ipc_specific_flags: ipc_specific_flags.clone(),
+        transfer_activity,
     };
-    
+
     let ai_response = match http_client
         .post(format!("{}/predict", ai_service_url))
         .json(&ai_request)
@@ -64,137 +139,220 @@ async fn check_wallet(
         Ok(response) => {
             if !response.status().is_success() {
                 error!("AI service returned error: {}", response.status());
-                return Err(ErrorInternalServerError("AI service error"));
+                return Err(CoreError::AiService("AI service error".to_string()));
             }
-            
-            match response.json::().await {
+
+            match response.json::<AIPredictionResponse>().await {
                 Ok(prediction) => prediction,
                 Err(e) => {
                     error!("Failed to parse AI response: {}", e);
-                    return Err(ErrorInternalServerError("Failed to parse AI response"));
+                    return Err(CoreError::AiService(
+                        "Failed to parse AI response".to_string(),
+                    ));
                 }
             }
         }
         Err(e) => {
             error!("Failed to connect to AI service: {}", e);
-            return Err(ErrorInternalServerError("Failed to connect to AI service"));
+            return Err(CoreError::AiService(
+                "Failed to connect to AI service".to_string(),
+            ));
         }
     };
-    
+
     // Update MongoDB record with AI prediction
-    if let Err(e) = db
-        .update_wallet_check(
-            &address,
-            &ai_response.risk_level,
-            &ai_response.reason,
-            ai_response.ipc_specific_flags.clone(),
-        )
-        .await
-    {
+    db.update_wallet_check(
+        &address,
+        &ai_response.risk_level,
+        &ai_response.reason,
+        ai_response.ipc_specific_flags.clone(),
+    )
+    .await
+    .map_err(|e| {
         error!("Failed to update wallet check: {}", e);
-        return Err(ErrorInternalServerError("Database error"));
-    }
-    
-    // Return response to client
-    let response = WalletCheckResponse {
+        CoreError::Database(e.to_string())
+    })?;
+
+    Ok(WalletCheckResponse {
         address,
         subnet_id,
         timestamp: Utc::now(),
         risk_level: ai_response.risk_level,
         reason: ai_response.reason,
         ipc_specific_flags: ai_response.ipc_specific_flags,
-    };
-    
-    Ok(HttpResponse::Ok().json(response))
+        resolved_from,
+    })
+}
+
+/// Core `/recent-checks` flow, shared by the REST handler and the
+/// `recent_checks` RPC method.
+pub async fn run_recent_checks(
+    db: &DbClient,
+    limit: i64,
+) -> std::result::Result<Vec<serde_json::Value>, CoreError> {
+    let checks = db.get_recent_checks(limit).await.map_err(|e| {
+        error!("Failed to get recent checks: {}", e);
+        CoreError::Database(e.to_string())
+    })?;
+
+    Ok(checks
+        .into_iter()
+        .map(|doc| {
+            let address = doc.get_str("address").unwrap_or("").to_string();
+            let subnet_id = doc.get_str("subnet_id").ok().map(|s| s.to_string());
+            let timestamp = doc.get_datetime("timestamp").unwrap_or(&bson::DateTime::now()).to_chrono();
+            let risk_level = doc.get_str("risk_level").unwrap_or("Unknown").to_string();
+            let reason = doc.get_str("reason").unwrap_or("").to_string();
+            let resolved_from = doc.get_str("resolved_from").ok().map(|s| s.to_string());
+
+            // Extract IPC-specific flags if present
+            let ipc_specific_flags = match doc.get_array("ipc_specific_flags") {
+                Ok(flags_array) => {
+                    let flags = flags_array
+                        .iter()
+                        .filter_map(|f| f.as_str().map(|s| s.to_string()))
+                        .collect::<Vec<String>>();
+
+                    if flags.is_empty() { None } else { Some(flags) }
+                },
+                Err(_) => None,
+            };
+
+            json!({
+                "address": address,
+                "subnet_id": subnet_id,
+                "timestamp": timestamp,
+                "risk_level": risk_level,
+                "reason": reason,
+                "ipc_specific_flags": ipc_specific_flags,
+                "resolved_from": resolved_from,
+            })
+        })
+        .collect())
+}
+
+/// Core `/subnet-stats/{subnet_id}` flow, shared by the REST handler and
+/// the `subnet_stats` RPC method.
+pub async fn run_subnet_stats(subnet_id: &str) -> std::result::Result<SubnetStats, CoreError> {
+    let client = IPCClient::new().map_err(|e| {
+        error!("Failed to create IPC client: {}", e);
+        CoreError::IpcUnavailable
+    })?;
+
+    let info = client.get_subnet_info(subnet_id).await.map_err(|e| {
+        error!("Failed to get subnet info: {}", e);
+        CoreError::Database(e.to_string())
+    })?;
+
+    Ok(SubnetStats {
+        id: info.id,
+        total_addresses: info.total_addresses,
+        active_validators: info.active_validators,
+        cross_subnet_txs: info.cross_subnet_txs,
+        risk_score: info.risk_score,
+    })
+}
+
+fn core_error_response(error: CoreError) -> actix_web::Error {
+    match error {
+        CoreError::InvalidAddress | CoreError::EnsResolutionFailed(_) => {
+            // Callers needing the JSON body (not just the status) should go
+            // through `run_check_wallet` directly, as `check_wallet` does.
+            ErrorInternalServerError("Invalid request")
+        }
+        CoreError::IpcUnavailable => ErrorInternalServerError("Failed to initialize IPC client"),
+        CoreError::Database(_) => ErrorInternalServerError("Database error"),
+        CoreError::AiService(msg) => ErrorInternalServerError(msg),
+    }
+}
+
+#[post("/check")]
+async fn check_wallet(
+    db: web::Data<DbClient>,
+    req: web::Json<WalletCheckRequest>,
+) -> Result<impl Responder> {
+    match run_check_wallet(&db, req.address.clone()).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(CoreError::InvalidAddress) => Ok(HttpResponse::BadRequest().json(json!({
+            "error": "Invalid IPC or Ethereum address format"
+        }))),
+        Err(CoreError::EnsResolutionFailed(name)) => Ok(HttpResponse::BadRequest().json(json!({
+            "error": format!("Failed to resolve ENS name {}", name)
+        }))),
+        Err(e) => Err(core_error_response(e)),
+    }
 }
 
 #[get("/recent-checks")]
-async fn get_recent_checks(db: web::Data, query: web::Query) -> Result {
+async fn get_recent_checks(
+    db: web::Data<DbClient>,
+    query: web::Query<RecentChecksQuery>,
+) -> Result<impl Responder> {
     let limit = query.limit.unwrap_or(10);
-    
-    match db.get_recent_checks(limit).await {
-        Ok(checks) => {
-            let response = checks
-                .into_iter()
-                .map(|doc| {
-                    let address = doc.get_str("address").unwrap_or("").to_string();
-                    let subnet_id = doc.get_str("subnet_id").ok().map(|s| s.to_string());
-                    let timestamp = doc.get_datetime("timestamp").unwrap_or(&bson::DateTime::now()).to_chrono();
-                    let risk_level = doc.get_str("risk_level").unwrap_or("Unknown").to_string();
-                    let reason = doc.get_str("reason").unwrap_or("").to_string();
-                    
-                    // Extract IPC-specific flags if present
-                    let ipc_specific_flags = match doc.get_array("ipc_specific_flags") {
-                        Ok(flags_array) => {
-                            let flags = flags_array
-                                .iter()
-                                .filter_map(|f| f.as_str().map(|s| s.to_string()))
-                                .collect::>();
-                            
-                            if flags.is_empty() { None } else { Some(flags) }
-                        },
-                        Err(_) => None,
-                    };
-                    
-                    json!({
-                        "address": address,
-                        "subnet_id": subnet_id,
-                        "timestamp": timestamp,
-                        "risk_level": risk_level,
-                        "reason": reason,
-                        "ipc_specific_flags": ipc_specific_flags,
-                    })
-                })
-                .collect::>();
-                
-            Ok(HttpResponse::Ok().json(response))
-        }
-        Err(e) => {
-            error!("Failed to get recent checks: {}", e);
-            Err(ErrorInternalServerError("Database error"))
-        }
+
+    match run_recent_checks(&db, limit).await {
+        Ok(checks) => Ok(HttpResponse::Ok().json(checks)),
+        Err(e) => Err(core_error_response(e)),
     }
 }
 
 #[get("/subnet-stats/{subnet_id}")]
-async fn get_subnet_stats(path: web::Path) -> Result {
-    let subnet_id = path.into_inner();
-    
-    match IPCClient::new() {
-        Ok(client) => {
-            match client.get_subnet_info(&subnet_id).await {
-                Ok(info) => {
-                    let stats = SubnetStats {
-                        id: info.id,
-                        total_addresses: info.total_addresses,
-                        active_validators: info.active_validators,
-                        cross_subnet_txs: info.cross_subnet_txs,
-                        risk_score: info.risk_score,
-                    };
-                    
-                    Ok(HttpResponse::Ok().json(stats))
-                },
-                Err(e) => {
-                    error!("Failed to get subnet info: {}", e);
-                    Err(ErrorInternalServerError("Failed to get subnet information"))
-                }
-            }
-        },
+async fn get_subnet_stats(path: web::Path<String>) -> Result<impl Responder> {
+    match run_subnet_stats(&path.into_inner()).await {
+        Ok(stats) => Ok(HttpResponse::Ok().json(stats)),
+        Err(e) => Err(core_error_response(e)),
+    }
+}
+
+#[post("/watch")]
+async fn watch_address(
+    db: web::Data<DbClient>,
+    req: web::Json<WatchRequest>,
+) -> Result<impl Responder> {
+    if !is_valid_ipc_address(&req.address) {
+        return Ok(HttpResponse::BadRequest().json(json!({
+            "error": "Invalid IPC or Ethereum address format"
+        })));
+    }
+
+    match db.add_watch_address(&req.address).await {
+        Ok(()) => Ok(HttpResponse::Ok().json(json!({
+            "status": "watching",
+            "address": req.address,
+        }))),
         Err(e) => {
-            error!("Failed to create IPC client: {}", e);
-            Err(ErrorInternalServerError("Failed to initialize IPC client"))
+            error!("Failed to add watch address: {}", e);
+            Err(ErrorInternalServerError("Database error"))
         }
     }
 }
 
+#[get("/alerts")]
+async fn stream_alerts(alert_tx: web::Data<AlertSender>) -> impl Responder {
+    let rx = alert_tx.subscribe();
+    let stream = BroadcastStream::new(rx).filter_map(|item| async move {
+        let check = item.ok()?;
+        let payload = serde_json::to_string(&check).ok()?;
+        Some(Ok::<_, actix_web::Error>(web::Bytes::from(format!(
+            "data: {}\n\n",
+            payload
+        ))))
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .streaming(stream)
+}
+
 #[derive(serde::Deserialize)]
 struct RecentChecksQuery {
-    limit: Option,
+    limit: Option<i64>,
 }
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(check_wallet)
        .service(get_recent_checks)
-       .service(get_subnet_stats);
+       .service(get_subnet_stats)
+       .service(watch_address)
+       .service(stream_alerts);
 }
-      
\ No newline at end of file
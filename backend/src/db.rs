@@ -1,8 +1,9 @@
 
         
-        use mongodb::{
+        use chrono::Utc;
+use mongodb::{
     bson::{doc, Document, to_bson},
-    options::ClientOptions,
+    options::{ClientOptions, UpdateOptions},
     Client, Collection, Database,
 };
 use std::env;
@@ -93,6 +94,32 @@ impl DbClient {
         
         Ok(documents)
     }
+
+    pub async fn add_watch_address(&self, address: &str) -> Result<(), DbError> {
+        let collection = self.get_collection("watchlist");
+
+        let filter = doc! { "address": address };
+        let update = doc! {
+            "$set": { "address": address },
+            "$setOnInsert": { "added_at": Utc::now() },
+        };
+        let options = UpdateOptions::builder().upsert(true).build();
+
+        collection.update_one(filter, update, options).await?;
+        Ok(())
+    }
+
+    pub async fn get_watchlist(&self) -> Result<Vec<String>, DbError> {
+        let collection = self.get_collection("watchlist");
+
+        let cursor = collection.find(None, None).await?;
+        let documents: Vec<Document> = cursor.try_collect().await?;
+
+        Ok(documents
+            .into_iter()
+            .filter_map(|doc| doc.get_str("address").ok().map(|s| s.to_string()))
+            .collect())
+    }
 }
 
 pub async fn init_db() -> Result {
@@ -1,30 +1,56 @@
-    
-        use ethers::{
+use ethers::{
+    contract::abigen,
     prelude::*,
-    providers::{Http, Provider},
-    types::{Address, U256},
+    providers::{
+        Http, HttpRateLimitRetryPolicy, Provider, Quorum, QuorumProvider, RetryClient,
+        RetryClientBuilder, WeightedProvider,
+    },
+    types::{
+        trace::{Action, Trace, TraceFilterBuilder},
+        Address, BlockNumber, CallFrame, Filter, GethDebugBuiltInTracerType, GethDebugTracerType,
+        GethDebugTracingOptions, GethTrace, GethTraceFrame, NameOrAddress, H256, U256,
+    },
+    utils::keccak256,
 };
+use log::warn;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 
 use super::{extract_eth_address, extract_subnet_id};
 
+abigen!(
+    SubnetGatewayContract,
+    r#"[
+        function getValidatorSetSize() external view returns (uint256)
+        function getTotalMembership() external view returns (uint256)
+        function getCrossMsgCount() external view returns (uint256)
+        function getSubnetValidatorSetSize(address subnetActor) external view returns (uint256)
+        function getSubnetTotalMembership(address subnetActor) external view returns (uint256)
+        function getSubnetCrossMsgCount(address subnetActor) external view returns (uint256)
+    ]"#
+);
+
 #[derive(Error, Debug)]
 pub enum IPCClientError {
     #[error("Invalid address format")]
     InvalidAddress,
-    
+
     #[error("Provider error: {0}")]
     ProviderError(String),
-    
+
     #[error("Environment variable not found: {0}")]
     EnvVarError(#[from] std::env::VarError),
-    
+
     #[error("Ethers error: {0}")]
     EthersError(#[from] ethers::prelude::ProviderError),
+
+    #[error("Gateway contract call failed: {0}")]
+    ContractError(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,47 +69,167 @@ pub struct WalletInfo {
     pub eth_address: String,
     pub balance: String,
     pub tx_count: u64,
-    pub subnet_info: Option,
+    pub subnet_info: Option<SubnetInfo>,
+    pub transfer_activity: TransferActivity,
+}
+
+/// ERC-20 transfer-graph features for an address, aggregated from
+/// `Transfer` log scans. Patterns like fan-out dusting or a rapid drain
+/// show up here even when the native balance/nonce look unremarkable.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransferActivity {
+    pub distinct_counterparties: u64,
+    pub inbound_transfers: u64,
+    pub outbound_transfers: u64,
+    pub burst_detected: bool,
 }
 
+/// Each configured RPC endpoint is itself retried with backoff before its
+/// vote counts toward the quorum, so a single flaky/rate-limited node can't
+/// take the whole service down.
+type IpcProvider = QuorumProvider<RetryClient<Http>>;
+type IpcMiddleware = Provider<IpcProvider>;
+
+/// Binding to the IPC gateway/subnet-actor contract used to pull live
+/// subnet membership and cross-messaging stats via `eth_call`.
+struct SubnetContract {
+    contract: SubnetGatewayContract<IpcMiddleware>,
+}
+
+/// Block window `get_fraud_flags` walks when pulling traces for an address
+/// via `trace_filter`, which covers the whole range in bulk (one call for
+/// outgoing and one for incoming traces).
+const TRACE_BLOCK_WINDOW: u64 = 5_000;
+/// Block window for the `debug_traceTransaction` fallback, which has no bulk
+/// equivalent and costs one `eth_getBlockByNumber` per block plus one
+/// `debug_traceTransaction` per matching tx, so it uses a much smaller
+/// window than `TRACE_BLOCK_WINDOW`.
+const DEBUG_TRACE_BLOCK_WINDOW: u64 = 200;
+/// Distinct downstream callees in a single tx above which we flag fanout.
+const HIGH_FANOUT_THRESHOLD: usize = 10;
+/// Default block window scanned for ERC-20 transfer activity.
+pub(crate) const TRANSFER_ACTIVITY_BLOCK_WINDOW: u64 = 10_000;
+/// Most providers cap `eth_getLogs` ranges well below
+/// `TRANSFER_ACTIVITY_BLOCK_WINDOW`; scan in chunks of this many blocks to
+/// stay under the cap.
+const LOG_CHUNK_SIZE: u64 = 2_000;
+/// Transfers within this many blocks of each other count toward a burst.
+const BURST_BLOCK_SPAN: u64 = 10;
+/// Minimum transfers inside `BURST_BLOCK_SPAN` blocks to flag a burst.
+const BURST_TRANSFER_THRESHOLD: usize = 5;
+/// Retries applied per RPC endpoint before it's treated as down for a call.
+const MAX_RPC_RETRIES: u32 = 5;
+const INITIAL_RETRY_BACKOFF_MS: u64 = 250;
+
 pub struct IPCClient {
-    provider: Arc>,
+    provider: Arc<IpcMiddleware>,
+    gateway_contract: Option<SubnetContract>,
+    flagged_contracts: Vec<Address>,
 }
 
 impl IPCClient {
-    pub fn new() -> Result {
-        let ipc_rpc_url = env::var("IPC_RPC_URL")?;
-        let provider = Provider::::try_from(ipc_rpc_url)
-            .map_err(|e| IPCClientError::ProviderError(e.to_string()))?;
-            
+    pub fn new() -> Result<Self, IPCClientError> {
+        let endpoints: Vec<String> = env::var("IPC_RPC_URL")?
+            .split(',')
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty())
+            .collect();
+
+        if endpoints.is_empty() {
+            return Err(IPCClientError::ProviderError(
+                "IPC_RPC_URL contains no endpoints".to_string(),
+            ));
+        }
+
+        let weighted_providers = endpoints
+            .iter()
+            .map(|url| {
+                let http = Http::from_str(url)
+                    .map_err(|e| IPCClientError::ProviderError(e.to_string()))?;
+                let retry_client = RetryClientBuilder::default()
+                    .rate_limit_retries(MAX_RPC_RETRIES)
+                    .timeout_retries(MAX_RPC_RETRIES)
+                    .initial_backoff(Duration::from_millis(INITIAL_RETRY_BACKOFF_MS))
+                    .build(http, Box::new(HttpRateLimitRetryPolicy));
+                Ok(WeightedProvider::new(retry_client))
+            })
+            .collect::<Result<Vec<_>, IPCClientError>>()?;
+
+        // A single endpoint degrades this to a plain (retrying) provider in
+        // all but name: quorum of one provider is satisfied by that one
+        // provider's answer, so there's no extra round-trip or voting.
+        let quorum = if endpoints.len() == 1 {
+            Quorum::All
+        } else {
+            quorum_from_env()
+        };
+
+        let quorum_provider = QuorumProvider::builder()
+            .add_providers(weighted_providers)
+            .quorum(quorum)
+            .build();
+
+        let provider = Arc::new(Provider::new(quorum_provider));
+
+        let gateway_contract = match env::var("IPC_GATEWAY_ADDRESS") {
+            Ok(raw_address) => {
+                let gateway_address = Address::from_str(&raw_address)
+                    .map_err(|_| IPCClientError::InvalidAddress)?;
+                let contract = SubnetGatewayContract::new(gateway_address, provider.clone());
+                Some(SubnetContract { contract })
+            }
+            Err(_) => None,
+        };
+
+        let flagged_contracts = env::var("FLAGGED_CONTRACT_DENYLIST")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|raw| Address::from_str(raw.trim()).ok())
+            .collect();
+
         Ok(Self {
-            provider: Arc::new(provider),
+            provider,
+            gateway_contract,
+            flagged_contracts,
         })
     }
-    
-    pub async fn get_wallet_info(&self, address: &str) -> Result {
+
+    /// Resolves an ENS-style name (e.g. `vitalik.eth`) to an address via the
+    /// provider's configured ENS resolver.
+    pub async fn resolve_ens_name(&self, name: &str) -> Result<Address, IPCClientError> {
+        self.provider
+            .resolve_name(name)
+            .await
+            .map_err(|e| IPCClientError::ProviderError(e.to_string()))
+    }
+
+    pub async fn get_wallet_info(&self, address: &str) -> Result<WalletInfo, IPCClientError> {
         // Extract Ethereum address and subnet ID
         let eth_address = extract_eth_address(address)
             .ok_or(IPCClientError::InvalidAddress)?;
-            
+
         let subnet_id = extract_subnet_id(address)
             .unwrap_or_else(|| "default".to_string());
-            
+
         // Convert to ethers Address type
-        let eth_addr = Address::from_str(ð_address)
+        let eth_addr = Address::from_str(&eth_address)
             .map_err(|_| IPCClientError::InvalidAddress)?;
-            
+
         // Get balance and transaction count
         let balance = self.provider.get_balance(eth_addr, None).await?;
         let tx_count = self.provider.get_transaction_count(eth_addr, None).await?;
-        
+
         // Get subnet info if not default
         let subnet_info = if subnet_id != "default" {
             Some(self.get_subnet_info(&subnet_id).await?)
         } else {
             None
         };
-        
+
+        let transfer_activity = self
+            .get_transfer_activity(eth_addr, TRANSFER_ACTIVITY_BLOCK_WINDOW)
+            .await?;
+
         Ok(WalletInfo {
             address: address.to_string(),
             subnet_id,
@@ -91,26 +237,446 @@ impl IPCClient {
             balance: format_ether(balance),
             tx_count: tx_count.as_u64(),
             subnet_info,
+            transfer_activity,
         })
     }
-    
-    pub async fn get_subnet_info(&self, subnet_id: &str) -> Result {
-        // In a real implementation, this would query the IPC blockchain
-        // For this demo, we'll return mock data based on the subnet ID
-        
-        // Use subnet_id to generate deterministic values
+
+    pub async fn get_subnet_info(&self, subnet_id: &str) -> Result<SubnetInfo, IPCClientError> {
+        match &self.gateway_contract {
+            Some(subnet_contract) => self.get_subnet_info_onchain(subnet_id, subnet_contract).await,
+            None => Ok(self.get_subnet_info_mock(subnet_id)),
+        }
+    }
+
+    /// Queries the gateway/subnet-actor contract directly so `SubnetStats`
+    /// reflects the chain instead of a hash-derived guess. When `subnet_id`
+    /// parses as a subnet actor address, the subnet-keyed getters are used
+    /// so the numbers are scoped to that subnet; otherwise (e.g. the
+    /// "default" root-subnet id) we fall back to the gateway's aggregate
+    /// getters, which report across all subnets it manages.
+    async fn get_subnet_info_onchain(
+        &self,
+        subnet_id: &str,
+        subnet_contract: &SubnetContract,
+    ) -> Result<SubnetInfo, IPCClientError> {
+        let (active_validators, total_addresses, cross_subnet_txs) =
+            match Address::from_str(subnet_id).ok() {
+                Some(subnet_actor) => {
+                    let active_validators = subnet_contract
+                        .contract
+                        .get_subnet_validator_set_size(subnet_actor)
+                        .call()
+                        .await
+                        .map_err(|e| IPCClientError::ContractError(e.to_string()))?
+                        .as_u64();
+
+                    let total_addresses = subnet_contract
+                        .contract
+                        .get_subnet_total_membership(subnet_actor)
+                        .call()
+                        .await
+                        .map_err(|e| IPCClientError::ContractError(e.to_string()))?
+                        .as_u64();
+
+                    let cross_subnet_txs = subnet_contract
+                        .contract
+                        .get_subnet_cross_msg_count(subnet_actor)
+                        .call()
+                        .await
+                        .map_err(|e| IPCClientError::ContractError(e.to_string()))?
+                        .as_u64();
+
+                    (active_validators, total_addresses, cross_subnet_txs)
+                }
+                None => {
+                    let active_validators = subnet_contract
+                        .contract
+                        .get_validator_set_size()
+                        .call()
+                        .await
+                        .map_err(|e| IPCClientError::ContractError(e.to_string()))?
+                        .as_u64();
+
+                    let total_addresses = subnet_contract
+                        .contract
+                        .get_total_membership()
+                        .call()
+                        .await
+                        .map_err(|e| IPCClientError::ContractError(e.to_string()))?
+                        .as_u64();
+
+                    let cross_subnet_txs = subnet_contract
+                        .contract
+                        .get_cross_msg_count()
+                        .call()
+                        .await
+                        .map_err(|e| IPCClientError::ContractError(e.to_string()))?
+                        .as_u64();
+
+                    (active_validators, total_addresses, cross_subnet_txs)
+                }
+            };
+
+        // A subnet whose validator set is thin relative to its membership is
+        // easier to capture, so weight risk_score on that ratio.
+        let validator_ratio = if total_addresses == 0 {
+            0.0
+        } else {
+            active_validators as f64 / total_addresses as f64
+        };
+        let risk_score = (100.0 - (validator_ratio * 100.0)).clamp(0.0, 100.0) as u8;
+
+        Ok(SubnetInfo {
+            id: subnet_id.to_string(),
+            total_addresses,
+            active_validators,
+            cross_subnet_txs,
+            risk_score,
+        })
+    }
+
+    /// Fallback used when `IPC_GATEWAY_ADDRESS` is unset: deterministic
+    /// values derived from the subnet string hash.
+    fn get_subnet_info_mock(&self, subnet_id: &str) -> SubnetInfo {
         let hash_value = subnet_id.chars()
             .map(|c| c as u64)
-            .sum::();
-            
-        Ok(SubnetInfo {
+            .sum::<u64>();
+
+        SubnetInfo {
             id: subnet_id.to_string(),
             total_addresses: 1000 + (hash_value % 9000),
             active_validators: 10 + (hash_value % 90),
             cross_subnet_txs: 500 + (hash_value % 1500),
             risk_score: ((hash_value % 100) as u8).min(100),
+        }
+    }
+
+    /// Derives `ipc_specific_flags` for `address` from its recent internal
+    /// calls. Falls back to replaying individual transactions with the
+    /// `debug_traceTransaction` callTracer when the node doesn't support
+    /// `trace_filter`, and gives up quietly (returning an empty list rather
+    /// than erroring) only if neither is available, since traces are a
+    /// signal booster, not something the rest of the check flow should
+    /// depend on.
+    pub async fn get_fraud_flags(&self, address: &str) -> Result<Vec<String>, IPCClientError> {
+        let eth_address = extract_eth_address(address).ok_or(IPCClientError::InvalidAddress)?;
+        let addr = Address::from_str(&eth_address).map_err(|_| IPCClientError::InvalidAddress)?;
+
+        let to_block = self.provider.get_block_number().await?.as_u64();
+        let from_block = to_block.saturating_sub(TRACE_BLOCK_WINDOW);
+
+        match self.get_address_traces(addr, from_block, to_block).await {
+            Ok(traces) => Ok(self.derive_trace_flags(&traces)),
+            Err(e) => {
+                warn!(
+                    "trace_filter unavailable for {}, falling back to debug_traceTransaction: {}",
+                    address, e
+                );
+                let debug_from_block = to_block.saturating_sub(DEBUG_TRACE_BLOCK_WINDOW);
+                match self
+                    .get_fraud_flags_via_debug_trace(addr, debug_from_block, to_block)
+                    .await
+                {
+                    Ok(flags) => Ok(flags),
+                    Err(e) => {
+                        warn!(
+                            "debug_traceTransaction unavailable for {}, skipping trace-derived flags: {}",
+                            address, e
+                        );
+                        Ok(Vec::new())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fallback for `get_fraud_flags` on nodes without `trace_filter`: walks
+    /// `[from_block, to_block]` block by block, replays each transaction
+    /// touching `addr` with geth's call tracer, and derives the same flags
+    /// `derive_trace_flags` would from `trace_filter` output. A single
+    /// block or transaction that can't be fetched/traced is skipped with a
+    /// warning rather than discarding flags already found in this range,
+    /// since traces are a best-effort signal booster here too.
+    async fn get_fraud_flags_via_debug_trace(
+        &self,
+        addr: Address,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<String>, IPCClientError> {
+        let mut flags = HashSet::new();
+        let mut callees_by_tx: HashMap<H256, HashSet<Address>> = HashMap::new();
+
+        for block_number in from_block..=to_block {
+            let block = match self.provider.get_block_with_txs(block_number).await {
+                Ok(Some(block)) => block,
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("Failed to fetch block {} while tracing {:?}: {}", block_number, addr, e);
+                    continue;
+                }
+            };
+
+            for tx in block.transactions {
+                if tx.from != addr && tx.to != Some(addr) {
+                    continue;
+                }
+
+                match self.get_transaction_call_trace(tx.hash).await {
+                    Ok(GethTrace::Known(GethTraceFrame::CallTracer(frame))) => {
+                        self.walk_call_frame(&frame, addr, tx.hash, &mut flags, &mut callees_by_tx);
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!(
+                        "debug_traceTransaction failed for tx {:?}: {}",
+                        tx.hash, e
+                    ),
+                }
+            }
+        }
+
+        if callees_by_tx
+            .values()
+            .any(|callees| callees.len() > HIGH_FANOUT_THRESHOLD)
+        {
+            flags.insert("high_internal_call_fanout".to_string());
+        }
+
+        let mut flags: Vec<String> = flags.into_iter().collect();
+        flags.sort();
+        Ok(flags)
+    }
+
+    /// Recursively walks a callTracer frame and its nested `calls`, feeding
+    /// the same signals `derive_trace_flags` extracts from `trace_filter`
+    /// output. Only records a call frame as one of `addr`'s downstream
+    /// callees when `addr` itself placed that call (`frame.from == addr`),
+    /// mirroring `trace_filter`'s `from_address` match — calls unrelated
+    /// contracts make to each other further down the same transaction's
+    /// call tree don't count as `addr` having interacted with them.
+    fn walk_call_frame(
+        &self,
+        frame: &CallFrame,
+        addr: Address,
+        tx_hash: H256,
+        flags: &mut HashSet<String>,
+        callees_by_tx: &mut HashMap<H256, HashSet<Address>>,
+    ) {
+        if frame.from == addr {
+            if let Some(NameOrAddress::Address(to)) = frame.to {
+                callees_by_tx.entry(tx_hash).or_default().insert(to);
+                if self.flagged_contracts.contains(&to) {
+                    flags.insert("interacts_with_flagged_contract".to_string());
+                }
+            }
+
+            if frame.typ.eq_ignore_ascii_case("SELFDESTRUCT") {
+                flags.insert("self_destruct_observed".to_string());
+            }
+        }
+
+        for child in frame.calls.iter().flatten() {
+            self.walk_call_frame(child, addr, tx_hash, flags, callees_by_tx);
+        }
+    }
+
+    /// Pulls internal-call data for `addr` over `[from_block, to_block]` via
+    /// `trace_filter`, matching traces where `addr` is either the caller or
+    /// the callee. `trace_filter`'s `from_address`/`to_address` combine with
+    /// AND within a single call, so "caller or callee" needs two separate
+    /// queries merged together rather than one filter with both set.
+    pub async fn get_address_traces(
+        &self,
+        addr: Address,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<Trace>, IPCClientError> {
+        let outgoing_filter = TraceFilterBuilder::default()
+            .from_address(vec![addr])
+            .from_block(BlockNumber::Number(from_block.into()))
+            .to_block(BlockNumber::Number(to_block.into()))
+            .build();
+        let incoming_filter = TraceFilterBuilder::default()
+            .to_address(vec![addr])
+            .from_block(BlockNumber::Number(from_block.into()))
+            .to_block(BlockNumber::Number(to_block.into()))
+            .build();
+
+        let outgoing = self
+            .provider
+            .trace_filter(outgoing_filter)
+            .await
+            .map_err(|e| IPCClientError::ProviderError(e.to_string()))?;
+        let incoming = self
+            .provider
+            .trace_filter(incoming_filter)
+            .await
+            .map_err(|e| IPCClientError::ProviderError(e.to_string()))?;
+
+        let mut seen = HashSet::new();
+        let mut traces = Vec::with_capacity(outgoing.len() + incoming.len());
+        for trace in outgoing.into_iter().chain(incoming.into_iter()) {
+            if seen.insert((trace.transaction_hash, trace.trace_address.clone())) {
+                traces.push(trace);
+            }
+        }
+
+        Ok(traces)
+    }
+
+    /// Fallback for nodes without `trace_*`: replays a single transaction
+    /// with geth's built-in call tracer.
+    pub async fn get_transaction_call_trace(
+        &self,
+        tx_hash: H256,
+    ) -> Result<GethTrace, IPCClientError> {
+        let options = GethDebugTracingOptions {
+            tracer: Some(GethDebugTracerType::BuiltInTracer(
+                GethDebugBuiltInTracerType::CallTracer,
+            )),
+            ..Default::default()
+        };
+
+        self.provider
+            .debug_trace_transaction(tx_hash, options)
+            .await
+            .map_err(|e| IPCClientError::ProviderError(e.to_string()))
+    }
+
+    /// Scans `Transfer(address,address,uint256)` logs touching `addr` over
+    /// the last `blocks_back` blocks and aggregates counterparty/burst
+    /// features. Chunks the range to respect provider max-log-range limits.
+    pub async fn get_transfer_activity(
+        &self,
+        addr: Address,
+        blocks_back: u64,
+    ) -> Result<TransferActivity, IPCClientError> {
+        let to_block = self.provider.get_block_number().await?.as_u64();
+        let from_block = to_block.saturating_sub(blocks_back);
+
+        let transfer_topic = H256::from(keccak256(
+            "Transfer(address,address,uint256)".as_bytes(),
+        ));
+        let addr_topic = H256::from(addr);
+
+        let mut counterparties = HashSet::new();
+        let mut inbound_transfers = 0u64;
+        let mut outbound_transfers = 0u64;
+        let mut block_numbers = Vec::new();
+
+        let mut chunk_start = from_block;
+        while chunk_start <= to_block {
+            let chunk_end = (chunk_start + LOG_CHUNK_SIZE - 1).min(to_block);
+
+            let inbound_filter = Filter::new()
+                .topic0(transfer_topic)
+                .topic2(addr_topic)
+                .from_block(chunk_start)
+                .to_block(chunk_end);
+            let outbound_filter = Filter::new()
+                .topic0(transfer_topic)
+                .topic1(addr_topic)
+                .from_block(chunk_start)
+                .to_block(chunk_end);
+
+            let inbound_logs = self
+                .provider
+                .get_logs(&inbound_filter)
+                .await
+                .map_err(|e| IPCClientError::ProviderError(e.to_string()))?;
+            let outbound_logs = self
+                .provider
+                .get_logs(&outbound_filter)
+                .await
+                .map_err(|e| IPCClientError::ProviderError(e.to_string()))?;
+
+            inbound_transfers += inbound_logs.len() as u64;
+            outbound_transfers += outbound_logs.len() as u64;
+
+            for log in inbound_logs.iter().chain(outbound_logs.iter()) {
+                if let Some(sender) = log.topics.get(1) {
+                    counterparties.insert(*sender);
+                }
+                if let Some(receiver) = log.topics.get(2) {
+                    counterparties.insert(*receiver);
+                }
+                if let Some(block_number) = log.block_number {
+                    block_numbers.push(block_number.as_u64());
+                }
+            }
+
+            chunk_start = chunk_end + 1;
+        }
+
+        counterparties.remove(&addr_topic);
+
+        Ok(TransferActivity {
+            distinct_counterparties: counterparties.len() as u64,
+            inbound_transfers,
+            outbound_transfers,
+            burst_detected: has_transfer_burst(&block_numbers),
         })
     }
+
+    fn derive_trace_flags(&self, traces: &[Trace]) -> Vec<String> {
+        let mut flags = HashSet::new();
+        // Keyed by transaction, since fanout is a single-tx signal: an
+        // address that's merely busy across many transactions shouldn't
+        // trip the same flag as one tx that fans out to many callees.
+        let mut callees_by_tx: HashMap<Option<H256>, HashSet<Address>> = HashMap::new();
+
+        for trace in traces {
+            match &trace.action {
+                Action::Call(call) => {
+                    callees_by_tx
+                        .entry(trace.transaction_hash)
+                        .or_default()
+                        .insert(call.to);
+                    if self.flagged_contracts.contains(&call.to) {
+                        flags.insert("interacts_with_flagged_contract".to_string());
+                    }
+                }
+                Action::Suicide(_) => {
+                    flags.insert("self_destruct_observed".to_string());
+                }
+                Action::Create(_) | Action::Reward(_) => {}
+            }
+        }
+
+        if callees_by_tx
+            .values()
+            .any(|callees| callees.len() > HIGH_FANOUT_THRESHOLD)
+        {
+            flags.insert("high_internal_call_fanout".to_string());
+        }
+
+        let mut flags: Vec<String> = flags.into_iter().collect();
+        flags.sort();
+        flags
+    }
+}
+
+/// Reads the quorum weight threshold from `IPC_QUORUM_PERCENTAGE` (e.g. `67`
+/// for two-thirds agreement); defaults to requiring a simple majority.
+fn quorum_from_env() -> Quorum {
+    match env::var("IPC_QUORUM_PERCENTAGE")
+        .ok()
+        .and_then(|raw| raw.parse::<u8>().ok())
+    {
+        Some(percentage) => Quorum::Percentage(percentage),
+        None => Quorum::Majority,
+    }
+}
+
+fn has_transfer_burst(block_numbers: &[u64]) -> bool {
+    if block_numbers.len() < BURST_TRANSFER_THRESHOLD {
+        return false;
+    }
+
+    let mut sorted = block_numbers.to_vec();
+    sorted.sort_unstable();
+    sorted
+        .windows(BURST_TRANSFER_THRESHOLD)
+        .any(|window| window[window.len() - 1] - window[0] <= BURST_BLOCK_SPAN)
 }
 
 fn format_ether(wei: U256) -> String {
@@ -118,4 +684,3 @@ fn format_ether(wei: U256) -> String {
     let ether = wei_f / 1_000_000_000_000_000_000.0;
     format!("{:.6}", ether)
 }
-      
\ No newline at end of file
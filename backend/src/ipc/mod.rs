@@ -0,0 +1,40 @@
+pub mod client;
+pub mod monitor;
+
+/// IPC addresses are of the form `/<subnet_id>/<eth_address>`; a bare
+/// `0x...` address belongs to the root subnet and has no prefix.
+pub fn extract_subnet_id(address: &str) -> Option<String> {
+    let trimmed = address.trim_start_matches('/');
+    let (subnet, rest) = trimmed.split_once('/')?;
+    if is_eth_address(rest) {
+        Some(subnet.to_string())
+    } else {
+        None
+    }
+}
+
+pub fn extract_eth_address(address: &str) -> Option<String> {
+    let candidate = address.rsplit('/').next().unwrap_or(address);
+    if is_eth_address(candidate) {
+        Some(candidate.to_string())
+    } else {
+        None
+    }
+}
+
+pub fn is_valid_ipc_address(address: &str) -> bool {
+    extract_eth_address(address).is_some()
+}
+
+/// Suffixes recognized as an ENS-style name rather than a hex address.
+const ENS_SUFFIXES: &[&str] = &[".eth"];
+
+pub fn is_ens_name(address: &str) -> bool {
+    ENS_SUFFIXES.iter().any(|suffix| address.ends_with(suffix))
+}
+
+fn is_eth_address(candidate: &str) -> bool {
+    candidate.len() == 42
+        && candidate.starts_with("0x")
+        && candidate[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -0,0 +1,142 @@
+use ethers::providers::{Middleware, Provider, StreamExt, Ws};
+use log::{error, info, warn};
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::broadcast;
+
+use crate::db::DbClient;
+use crate::models::WalletCheck;
+use crate::routes::{run_check_wallet, CoreError};
+
+/// Channel used to fan watchlist hits out to `GET /alerts` subscribers.
+pub type AlertSender = broadcast::Sender<WalletCheck>;
+
+const ALERT_CHANNEL_CAPACITY: usize = 256;
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(5);
+/// `/alerts` only streams high-risk hits, so the dashboard isn't flooded
+/// with every rescore of a watched address.
+const ALERT_RISK_LEVEL: &str = "High";
+
+#[derive(Error, Debug)]
+enum MonitorError {
+    #[error("Environment variable not found: {0}")]
+    EnvVarError(#[from] std::env::VarError),
+
+    #[error("Provider error: {0}")]
+    ProviderError(String),
+
+    #[error("Check failed: {0:?}")]
+    CheckFailed(CoreError),
+
+    #[error("Block subscription ended")]
+    StreamEnded,
+}
+
+pub fn new_alert_channel() -> AlertSender {
+    broadcast::channel(ALERT_CHANNEL_CAPACITY).0
+}
+
+/// Spawns the watchlist monitor as a background task. Reconnects with a
+/// fixed delay whenever the WS subscription drops instead of giving up.
+pub fn spawn(db: DbClient, alert_tx: AlertSender) {
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = run_once(&db, &alert_tx).await {
+                error!("Watchlist monitor stopped: {}", e);
+            }
+            warn!("Reconnecting watchlist monitor in {:?}", RESUBSCRIBE_DELAY);
+            tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        }
+    });
+}
+
+async fn run_once(db: &DbClient, alert_tx: &AlertSender) -> Result<(), MonitorError> {
+    let ws_url = env::var("IPC_WS_URL")?;
+    let provider = Provider::<Ws>::connect(&ws_url)
+        .await
+        .map_err(|e| MonitorError::ProviderError(e.to_string()))?;
+    let provider = Arc::new(provider);
+
+    let mut new_heads = provider
+        .subscribe_blocks()
+        .await
+        .map_err(|e| MonitorError::ProviderError(e.to_string()))?;
+
+    info!("Watchlist monitor subscribed to new blocks at {}", ws_url);
+
+    while let Some(block) = new_heads.next().await {
+        let Some(block_hash) = block.hash else {
+            continue;
+        };
+
+        let full_block = match provider.get_block_with_txs(block_hash).await {
+            Ok(Some(block)) => block,
+            Ok(None) => continue,
+            Err(e) => {
+                error!("Failed to fetch block {:?}: {}", block_hash, e);
+                continue;
+            }
+        };
+
+        let watchlist = match db.get_watchlist().await {
+            Ok(addresses) => addresses,
+            Err(e) => {
+                error!("Failed to load watchlist: {}", e);
+                continue;
+            }
+        };
+
+        if watchlist.is_empty() {
+            continue;
+        }
+
+        for tx in full_block.transactions {
+            let from = format!("{:?}", tx.from);
+            let to = tx.to.map(|addr| format!("{:?}", addr));
+
+            let matched = watchlist.iter().find(|watched| {
+                watched.eq_ignore_ascii_case(&from)
+                    || to.as_deref()
+                        .map(|to| watched.eq_ignore_ascii_case(to))
+                        .unwrap_or(false)
+            });
+
+            if let Some(address) = matched {
+                if let Err(e) = rescore_watched_address(db, alert_tx, address).await {
+                    error!("Failed to rescore watched address {}: {}", address, e);
+                }
+            }
+        }
+    }
+
+    Err(MonitorError::StreamEnded)
+}
+
+/// Runs the same check-and-score flow as `POST /check`, but triggered by a
+/// block matching a watched address instead of an on-demand request. Goes
+/// through the shared `run_check_wallet` core so this doesn't drift from the
+/// REST/RPC surfaces (ENS resolution, subnet id, trace/transfer signals).
+async fn rescore_watched_address(
+    db: &DbClient,
+    alert_tx: &AlertSender,
+    address: &str,
+) -> Result<(), MonitorError> {
+    let response = run_check_wallet(db, address.to_string())
+        .await
+        .map_err(MonitorError::CheckFailed)?;
+
+    if response.risk_level.eq_ignore_ascii_case(ALERT_RISK_LEVEL) {
+        let mut alert = WalletCheck::new(response.address, response.subnet_id);
+        alert.risk_level = Some(response.risk_level);
+        alert.reason = Some(response.reason);
+        alert.ipc_specific_flags = response.ipc_specific_flags;
+        alert.resolved_from = response.resolved_from;
+
+        // No subscribers is the common case when no dashboard is connected.
+        let _ = alert_tx.send(alert);
+    }
+
+    Ok(())
+}
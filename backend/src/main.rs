@@ -4,10 +4,12 @@ use actix_web::{App, HttpServer, middleware::Logger};
 use dotenv::dotenv;
 use std::env;
 
+#[path = "backen.rs"]
 mod routes;
 mod models;
 mod db;
 mod ipc;
+mod rpc;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -22,18 +24,25 @@ async fn main() -> std::io::Result<()> {
     
     // Initialize database connection
     let db = db::init_db().await.expect("Failed to connect to database");
-    
+
+    // Background subscription that auto-rescores watchlisted addresses as
+    // new blocks arrive, and the channel `/alerts` streams hits from.
+    let alert_tx = ipc::monitor::new_alert_channel();
+    ipc::monitor::spawn(db.clone(), alert_tx.clone());
+
     HttpServer::new(move || {
         let cors = Cors::default()
             .allow_any_origin()
             .allow_any_method()
             .allow_any_header();
-            
+
         App::new()
             .wrap(cors)
             .wrap(Logger::default())
             .app_data(actix_web::web::Data::new(db.clone()))
+            .app_data(actix_web::web::Data::new(alert_tx.clone()))
             .configure(routes::config)
+            .configure(rpc::config)
     })
     .bind(server_url)?
     .run()
@@ -1,26 +1,36 @@
- use chrono::{DateTime, Utc};
+use chrono::{DateTime, Utc};
 use mongodb::bson::{self, doc, Document};
 use serde::{Deserialize, Serialize};
 
+use crate::ipc::client::TransferActivity;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WalletCheckRequest {
     pub address: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WatchRequest {
+    pub address: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WalletCheck {
     #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
-    pub id: Option,
+    pub id: Option<bson::oid::ObjectId>,
     pub address: String,
-    pub subnet_id: Option,
-    pub timestamp: DateTime,
-    pub risk_level: Option,
-    pub reason: Option,
-    pub ipc_specific_flags: Option>,
+    pub subnet_id: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub risk_level: Option<String>,
+    pub reason: Option<String>,
+    pub ipc_specific_flags: Option<Vec<String>>,
+    /// The ENS-style name this check was originally requested with, when
+    /// `address` is a resolved lookup rather than a raw address.
+    pub resolved_from: Option<String>,
 }
 
 impl WalletCheck {
-    pub fn new(address: String, subnet_id: Option) -> Self {
+    pub fn new(address: String, subnet_id: Option<String>) -> Self {
         Self {
             id: None,
             address,
@@ -29,9 +39,10 @@ impl WalletCheck {
             risk_level: None,
             reason: None,
             ipc_specific_flags: None,
+            resolved_from: None,
         }
     }
-    
+
     pub fn into_document(self) -> Document {
         doc! {
             "address": self.address,
@@ -40,6 +51,7 @@ impl WalletCheck {
             "risk_level": self.risk_level,
             "reason": self.reason,
             "ipc_specific_flags": self.ipc_specific_flags,
+            "resolved_from": self.resolved_from,
         }
     }
 }
@@ -47,24 +59,27 @@ impl WalletCheck {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AIPredictionRequest {
     pub address: String,
-    pub subnet_id: Option,
+    pub subnet_id: Option<String>,
+    pub ipc_specific_flags: Option<Vec<String>>,
+    pub transfer_activity: Option<TransferActivity>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AIPredictionResponse {
     pub risk_level: String,
     pub reason: String,
-    pub ipc_specific_flags: Option>,
+    pub ipc_specific_flags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WalletCheckResponse {
     pub address: String,
-    pub subnet_id: Option,
-    pub timestamp: DateTime,
+    pub subnet_id: Option<String>,
+    pub timestamp: DateTime<Utc>,
     pub risk_level: String,
     pub reason: String,
-    pub ipc_specific_flags: Option>,
+    pub ipc_specific_flags: Option<Vec<String>>,
+    pub resolved_from: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -75,7 +90,3 @@ pub struct SubnetStats {
     pub cross_subnet_txs: u64,
     pub risk_score: u8,
 }
-      
-
-      
-      
\ No newline at end of file
@@ -0,0 +1,206 @@
+use actix_web::{post, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::routes::{run_check_wallet, run_recent_checks, run_subnet_stats, CoreError};
+use crate::db::DbClient;
+
+const JSONRPC_VERSION: &str = "2.0";
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Debug, Deserialize)]
+struct RpcRequest {
+    jsonrpc: Option<String>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+    #[serde(default)]
+    id: Value,
+}
+
+#[derive(Debug, Serialize)]
+struct RpcErrorObject {
+    code: i64,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcErrorObject>,
+    id: Value,
+}
+
+impl RpcResponse {
+    fn ok(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            result: Some(result),
+            error: None,
+            id,
+        }
+    }
+
+    fn err(id: Value, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            result: None,
+            error: Some(RpcErrorObject {
+                code,
+                message: message.into(),
+            }),
+            id,
+        }
+    }
+}
+
+/// Mirrors `check_wallet`/`recent_checks`/`subnet_stats` over a single
+/// batchable JSON-RPC 2.0 endpoint so non-HTTP-REST clients can integrate
+/// against the same `DbClient`/`IPCClient` logic the REST routes use.
+#[post("/rpc")]
+async fn json_rpc(db: web::Data<DbClient>, body: web::Json<Value>) -> impl Responder {
+    match body.into_inner() {
+        Value::Array(requests) if requests.is_empty() => HttpResponse::Ok().json(
+            RpcResponse::err(Value::Null, INVALID_REQUEST, "Invalid Request"),
+        ),
+        Value::Array(requests) => {
+            let mut responses = Vec::with_capacity(requests.len());
+            for raw in requests {
+                if let Some(response) = handle_one(&db, raw).await {
+                    responses.push(response);
+                }
+            }
+            // Per spec, a batch of only notifications gets no body at all.
+            if responses.is_empty() {
+                HttpResponse::NoContent().finish()
+            } else {
+                HttpResponse::Ok().json(responses)
+            }
+        }
+        single => match handle_one(&db, single).await {
+            Some(response) => HttpResponse::Ok().json(response),
+            None => HttpResponse::NoContent().finish(),
+        },
+    }
+}
+
+/// Returns `None` for notifications (a request object with no `id` member),
+/// which per the JSON-RPC 2.0 spec get no response at all.
+async fn handle_one(db: &DbClient, raw: Value) -> Option<RpcResponse> {
+    let is_notification = raw.get("id").is_none();
+
+    // `raw` is already-parsed JSON (actix's `web::Json<Value>` extractor
+    // would have rejected anything that doesn't parse), so a failure here
+    // means the object is well-formed JSON but not a well-formed request
+    // (e.g. missing `method`) — that's Invalid Request, not Parse error.
+    let req: RpcRequest = match serde_json::from_value(raw) {
+        Ok(req) => req,
+        Err(_) => return Some(RpcResponse::err(Value::Null, INVALID_REQUEST, "Invalid Request")),
+    };
+
+    if req.jsonrpc.as_deref() != Some(JSONRPC_VERSION) {
+        return if is_notification {
+            None
+        } else {
+            Some(RpcResponse::err(req.id, INVALID_REQUEST, "Invalid Request"))
+        };
+    }
+
+    let id = req.id.clone();
+
+    let response = match req.method.as_str() {
+        "check_wallet" => dispatch_check_wallet(db, req.params, id).await,
+        "recent_checks" => dispatch_recent_checks(db, req.params, id).await,
+        "subnet_stats" => dispatch_subnet_stats(req.params, id).await,
+        _ => RpcResponse::err(id, METHOD_NOT_FOUND, "Method not found"),
+    };
+
+    if is_notification {
+        None
+    } else {
+        Some(response)
+    }
+}
+
+async fn dispatch_check_wallet(db: &DbClient, params: Value, id: Value) -> RpcResponse {
+    #[derive(Deserialize)]
+    struct Params {
+        address: String,
+    }
+
+    let params: Params = match serde_json::from_value(params) {
+        Ok(p) => p,
+        Err(_) => return RpcResponse::err(id, INVALID_PARAMS, "Invalid params: expected { address }"),
+    };
+
+    match run_check_wallet(db, params.address).await {
+        Ok(response) => RpcResponse::ok(id, serde_json::to_value(response).unwrap_or(Value::Null)),
+        Err(e) => core_error_to_rpc(id, e),
+    }
+}
+
+async fn dispatch_recent_checks(db: &DbClient, params: Value, id: Value) -> RpcResponse {
+    #[derive(Deserialize, Default)]
+    struct Params {
+        limit: Option<i64>,
+    }
+
+    let params: Params = if params.is_null() {
+        Params::default()
+    } else {
+        match serde_json::from_value(params) {
+            Ok(p) => p,
+            Err(_) => return RpcResponse::err(id, INVALID_PARAMS, "Invalid params: expected { limit? }"),
+        }
+    };
+
+    match run_recent_checks(db, params.limit.unwrap_or(10)).await {
+        Ok(checks) => RpcResponse::ok(id, Value::Array(checks)),
+        Err(e) => core_error_to_rpc(id, e),
+    }
+}
+
+async fn dispatch_subnet_stats(params: Value, id: Value) -> RpcResponse {
+    #[derive(Deserialize)]
+    struct Params {
+        subnet_id: String,
+    }
+
+    let params: Params = match serde_json::from_value(params) {
+        Ok(p) => p,
+        Err(_) => return RpcResponse::err(id, INVALID_PARAMS, "Invalid params: expected { subnet_id }"),
+    };
+
+    match run_subnet_stats(&params.subnet_id).await {
+        Ok(stats) => RpcResponse::ok(id, serde_json::to_value(stats).unwrap_or(Value::Null)),
+        Err(e) => core_error_to_rpc(id, e),
+    }
+}
+
+fn core_error_to_rpc(id: Value, error: CoreError) -> RpcResponse {
+    match error {
+        CoreError::InvalidAddress => {
+            RpcResponse::err(id, INVALID_PARAMS, "Invalid IPC or Ethereum address format")
+        }
+        CoreError::EnsResolutionFailed(name) => RpcResponse::err(
+            id,
+            INVALID_PARAMS,
+            format!("Failed to resolve ENS name {}", name),
+        ),
+        CoreError::IpcUnavailable => {
+            RpcResponse::err(id, INTERNAL_ERROR, "Failed to initialize IPC client")
+        }
+        CoreError::Database(msg) => RpcResponse::err(id, INTERNAL_ERROR, msg),
+        CoreError::AiService(msg) => RpcResponse::err(id, INTERNAL_ERROR, msg),
+    }
+}
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(json_rpc);
+}
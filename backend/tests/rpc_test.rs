@@ -0,0 +1,175 @@
+//! End-to-end tests for the `/rpc` JSON-RPC 2.0 surface: spins up the real
+//! server binary and exercises it over HTTP, the same way a dedicated RPC
+//! server test suite would.
+
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use reqwest::Client;
+use serde_json::{json, Value};
+
+const TEST_PORT: &str = "18080";
+
+struct TestServer {
+    child: Child,
+    base_url: String,
+}
+
+impl TestServer {
+    async fn start() -> Self {
+        let child = Command::new(env!("CARGO_BIN_EXE_backend"))
+            .envs(std::env::vars())
+            .env("HOST", "127.0.0.1")
+            .env("PORT", TEST_PORT)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("failed to start backend server binary");
+
+        let server = Self {
+            child,
+            base_url: format!("http://127.0.0.1:{}", TEST_PORT),
+        };
+        server.wait_until_ready().await;
+        server
+    }
+
+    async fn wait_until_ready(&self) {
+        let client = Client::new();
+        for _ in 0..50 {
+            if client
+                .get(format!("{}/recent-checks", self.base_url))
+                .send()
+                .await
+                .is_ok()
+            {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("backend server did not become ready in time");
+    }
+
+    async fn rpc(&self, request: &Value) -> Value {
+        Client::new()
+            .post(format!("{}/rpc", self.base_url))
+            .json(request)
+            .send()
+            .await
+            .expect("request to /rpc failed")
+            .json()
+            .await
+            .expect("response was not valid JSON")
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+    }
+}
+
+#[tokio::test]
+async fn subnet_stats_round_trips_through_rpc() {
+    let server = TestServer::start().await;
+
+    let response = server
+        .rpc(&json!({
+            "jsonrpc": "2.0",
+            "method": "subnet_stats",
+            "params": { "subnet_id": "test-subnet" },
+            "id": 1,
+        }))
+        .await;
+
+    assert_eq!(response["jsonrpc"], "2.0");
+    assert_eq!(response["id"], 1);
+    assert!(response["result"]["id"].is_string());
+}
+
+#[tokio::test]
+async fn unknown_method_returns_method_not_found() {
+    let server = TestServer::start().await;
+
+    let response = server
+        .rpc(&json!({
+            "jsonrpc": "2.0",
+            "method": "not_a_real_method",
+            "params": {},
+            "id": "abc",
+        }))
+        .await;
+
+    assert_eq!(response["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn request_missing_method_returns_invalid_request() {
+    let server = TestServer::start().await;
+
+    let response = server
+        .rpc(&json!({
+            "jsonrpc": "2.0",
+            "params": {},
+            "id": 1,
+        }))
+        .await;
+
+    assert_eq!(response["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn check_wallet_with_invalid_address_returns_invalid_params() {
+    let server = TestServer::start().await;
+
+    let response = server
+        .rpc(&json!({
+            "jsonrpc": "2.0",
+            "method": "check_wallet",
+            "params": { "address": "not-an-address" },
+            "id": 2,
+        }))
+        .await;
+
+    assert_eq!(response["error"]["code"], -32602);
+}
+
+#[tokio::test]
+async fn empty_batch_returns_invalid_request_error() {
+    let server = TestServer::start().await;
+
+    let response = server.rpc(&json!([])).await;
+
+    assert_eq!(response["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn notification_without_id_gets_no_content_response() {
+    let server = TestServer::start().await;
+
+    let response = Client::new()
+        .post(format!("{}/rpc", server.base_url))
+        .json(&json!({ "jsonrpc": "2.0", "method": "recent_checks", "params": {} }))
+        .send()
+        .await
+        .expect("request to /rpc failed");
+
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn batch_requests_return_matching_array() {
+    let server = TestServer::start().await;
+
+    let batch = json!([
+        { "jsonrpc": "2.0", "method": "recent_checks", "params": { "limit": 1 }, "id": 1 },
+        { "jsonrpc": "2.0", "method": "subnet_stats", "params": { "subnet_id": "test-subnet" }, "id": 2 },
+    ]);
+
+    let response = server.rpc(&batch).await;
+
+    let responses = response.as_array().expect("batch response should be an array");
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["id"], 1);
+    assert_eq!(responses[1]["id"], 2);
+}